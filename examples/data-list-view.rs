@@ -8,6 +8,8 @@ use std::collections::HashMap;
 enum Control {
     Select(usize),
     Update(usize, String),
+    Copy(usize),
+    Paste(usize),
 }
 
 #[derive(Debug)]
@@ -43,6 +45,9 @@ impl MyData {
                 self.last_key = self.last_key.max(index);
                 self.strings.insert(index, text);
             }
+            Control::Copy(_) | Control::Paste(_) => {
+                unreachable!("handled directly by the window's message handler")
+            }
         };
     }
 }
@@ -77,6 +82,7 @@ mod ListEntry {
     #[layout(column! [
         row! [self.label, self.radio],
         self.edit,
+        self.buttons,
     ])]
     struct ListEntry {
         core: widget_core!(),
@@ -86,6 +92,8 @@ mod ListEntry {
         radio: RadioButton<MyItem>,
         #[widget]
         edit: EditBox<ListEntryGuard>,
+        #[widget(&())]
+        buttons: Row<[Button<AccessLabel>; 2]>,
     }
 
     impl Events for Self {
@@ -108,6 +116,10 @@ impl Driver<usize, MyItem> for ListEntryDriver {
                 move || Control::Select(n),
             ),
             edit: EditBox::new(ListEntryGuard(n)).with_width_em(18.0, 30.0),
+            buttons: Row::new([
+                Button::label_msg("Copy", Control::Copy(n)),
+                Button::label_msg("Paste", Control::Paste(n)),
+            ]),
         }
     }
 
@@ -154,9 +166,16 @@ fn main() -> kas::runner::Result<()> {
         ScrollBars::new(list).with_fixed_bars(false, true),
     ];
 
-    let ui = tree
-        .with_state(MyData::new())
-        .on_message(|_, data, control| data.handle(control));
+    let ui = tree.with_state(MyData::new()).on_message(|cx, data, control| {
+        match control {
+            Control::Copy(index) => cx.set_clipboard(data.get_string(index)),
+            Control::Paste(index) => match cx.get_clipboard() {
+                Some(text) => data.handle(Control::Update(index, text)),
+                None => log::info!("clipboard is empty; nothing to paste"),
+            },
+            other => data.handle(other),
+        }
+    });
 
     let window = Window::new(ui, "Data list view");
 
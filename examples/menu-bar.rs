@@ -0,0 +1,98 @@
+use kas::prelude::*;
+use kas::widgets::menu::{MenuBar, MenuEntry, MenuToggle, SubMenu};
+use kas::widgets::{Separator, Text, column};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+enum Align {
+    #[default]
+    Left,
+    Centre,
+    Right,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct MenuState {
+    bold: bool,
+    align: Align,
+}
+
+#[derive(Clone, Debug)]
+enum Control {
+    New,
+    Open,
+    Quit,
+    Bold,
+    Align(Align),
+}
+
+fn menu() -> impl Widget<Data = MenuState> {
+    let file = SubMenu::new(
+        "&File",
+        vec![
+            MenuEntry::new("&New", Control::New).boxed(),
+            MenuEntry::new("&Open", Control::Open).boxed(),
+            Separator::new().boxed(),
+            MenuEntry::new("&Quit", Control::Quit).boxed(),
+        ],
+    );
+
+    // A radio-style group: exactly one of these is checked at a time,
+    // reflecting `MenuState::align`.
+    let align = SubMenu::new(
+        "&Align",
+        vec![
+            MenuToggle::new("&Left", |_, state: &MenuState| state.align == Align::Left)
+                .with_msg(|_| Control::Align(Align::Left))
+                .boxed(),
+            MenuToggle::new("&Centre", |_, state: &MenuState| {
+                state.align == Align::Centre
+            })
+            .with_msg(|_| Control::Align(Align::Centre))
+            .boxed(),
+            MenuToggle::new("&Right", |_, state: &MenuState| state.align == Align::Right)
+                .with_msg(|_| Control::Align(Align::Right))
+                .boxed(),
+        ],
+    );
+
+    let format = SubMenu::new(
+        "F&ormat",
+        vec![
+            MenuToggle::new("&Bold", |_, state: &MenuState| state.bold)
+                .with_msg(|_| Control::Bold)
+                .boxed(),
+            Separator::new().boxed(),
+            align.boxed(),
+        ],
+    );
+
+    let bar = MenuBar::new(vec![file.boxed(), format.boxed()]);
+
+    column![
+        bar,
+        Separator::new(),
+        Text::new(|_, _: &MenuState| "Use the menu bar above."),
+    ]
+}
+
+fn main() -> kas::runner::Result<()> {
+    env_logger::init();
+
+    let tree = menu().with_state(MenuState::default()).on_message(|_, state, control| {
+        // `SubMenu` keeps its parent entry visually depressed for as long
+        // as its popup is open, and closes automatically on selection or
+        // on focus loss. The toggle/radio state above needs updating here
+        // so the checkmarks reflect what was actually clicked.
+        match control {
+            Control::Bold => state.bold = !state.bold,
+            Control::Align(align) => state.align = align,
+            other => log::info!("menu selection: {other:?}"),
+        }
+    });
+
+    let theme = kas::theme::ShadedTheme::new();
+    let mut app = kas::runner::Runner::with_theme(theme).build(())?;
+    let _ = app.config_mut().font.set_size(24.0);
+    let window = Window::new(tree, "Menu bar").escapable();
+    app.with(window).run()
+}
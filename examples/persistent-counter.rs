@@ -0,0 +1,62 @@
+use kas::widgets::{AdaptWidget, Button, Label, Slider, column, format_data, row};
+use kas::window::Window;
+use serde::{Deserialize, Serialize};
+
+#[path = "util/persist.rs"]
+mod persist;
+use persist::{load_state, save_state};
+
+#[derive(Clone, Debug)]
+struct Increment(i32);
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+struct Count(i32);
+impl kas::runner::AppData for Count {
+    fn handle_messages(&mut self, messages: &mut kas::runner::MessageStack) {
+        if let Some(Increment(add)) = messages.try_pop() {
+            self.0 += add;
+            save_state(STATE_PATH, self);
+        }
+    }
+}
+
+const STATE_PATH: &str = "persistent-counter.ron";
+
+fn counter(title: &str) -> Window<Count> {
+    // Per window state: (count, increment).
+    type Data = (Count, i32);
+    let initial: Data = (Count(0), 1);
+
+    #[derive(Clone, Debug)]
+    struct SetValue(i32);
+
+    let slider = Slider::right(1..=10, |_, data: &Data| data.1).with_msg(SetValue);
+    let ui = column![
+        format_data!(data: &Data, "Count: {}", data.0.0),
+        row![slider, format_data!(data: &Data, "{}", data.1)],
+        row![
+            Button::new(Label::new_any("Sub")).with(|cx, data: &Data| cx.push(Increment(-data.1))),
+            Button::new(Label::new_any("Add")).with(|cx, data: &Data| cx.push(Increment(data.1))),
+        ],
+    ];
+
+    let ui = ui
+        .with_state(initial)
+        .on_update(|_, state, count| state.0 = *count)
+        .on_message(|_, state, SetValue(v)| state.1 = v);
+    Window::new(ui, title).escapable()
+}
+
+fn main() -> kas::runner::Result<()> {
+    env_logger::init();
+
+    // Restore the last saved count (if any) before building the app, and
+    // flush back to disk from `AppData::handle_messages` above whenever
+    // it changes, so the counter survives restarts.
+    let count: Count = load_state(STATE_PATH);
+    let theme = kas_wgpu::ShadedTheme::new();
+
+    let mut runner = kas::runner::Runner::with_theme(theme).build(count)?;
+    let _ = runner.config_mut().font.set_size(24.0);
+    runner.with(counter("Persistent counter")).run()
+}
@@ -0,0 +1,62 @@
+use kas::prelude::*;
+use kas::theme::ShadedTheme;
+use kas::widgets::{ComboBox, Slider, column, row};
+
+// Note: `kas::runner::Runner` is generic over a single, fixed `Theme`
+// implementation, so swapping e.g. `SimpleTheme` for `ShadedTheme` at
+// runtime isn't supported — that choice is made once, at startup. What
+// *is* live-switchable without restarting is a theme's colour scheme and
+// its font size, both stored in `Config` and re-read on the next draw.
+// This tutorial demonstrates that supported subset.
+
+#[derive(Clone, Debug)]
+enum Control {
+    SetScheme(usize),
+    SetFontSize(i32),
+}
+
+const SCHEMES: &[&str] = &["dark", "light", "blue"];
+
+// State: (index of the active colour scheme, font size).
+type State = (usize, i32);
+
+fn switcher() -> impl Widget<Data = ()> {
+    let scheme = ComboBox::new(
+        SCHEMES
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.to_string(), Control::SetScheme(i))),
+        |_, state: &State| state.0,
+    );
+    let size = Slider::right(10..=40, |_, state: &State| state.1).with_msg(Control::SetFontSize);
+
+    let tree = column![row!["Colour scheme:", scheme], row!["Font size:", size]];
+
+    tree.with_state((0, 24)).on_message(|cx, state, control| {
+        match control {
+            Control::SetScheme(i) => {
+                state.0 = i;
+                cx.config_mut().theme.set_active_scheme(SCHEMES[i]);
+            }
+            Control::SetFontSize(size) => {
+                state.1 = size;
+                let _ = cx.config_mut().font.set_size(size as f32);
+            }
+        }
+        // Force all open windows to re-layout with the new config.
+        cx.action(Id::ROOT, Action::RESIZE);
+    })
+}
+
+fn main() -> kas::runner::Result<()> {
+    env_logger::init();
+
+    let theme = ShadedTheme::new();
+    let mut app = kas::runner::Runner::with_theme(theme).build(())?;
+    let _ = app.config_mut().font.set_size(24.0);
+    // Seed the active scheme so the combo box's initial selection
+    // (`State`'s default index 0, i.e. `SCHEMES[0]`) matches reality.
+    app.config_mut().theme.set_active_scheme(SCHEMES[0]);
+    let window = Window::new(switcher(), "Theme switcher").escapable();
+    app.with(window).run()
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::{fs, io};
+
+/// On-disk format, chosen from the save file's extension. Mirrors KAS's
+/// own config serialization, which supports RON, JSON and YAML.
+pub enum Format {
+    Ron,
+    Json,
+    Yaml,
+}
+
+impl Format {
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Format::Json,
+            Some("yaml") | Some("yml") => Format::Yaml,
+            _ => Format::Ron,
+        }
+    }
+}
+
+/// Load `T` from `path`, falling back to `T::default()` if the file is
+/// missing or fails to parse.
+pub fn load_state<T: Default + for<'de> Deserialize<'de>>(path: impl AsRef<Path>) -> T {
+    let path = path.as_ref();
+    let Ok(content) = fs::read_to_string(path) else {
+        return T::default();
+    };
+    let result = match Format::from_path(path) {
+        Format::Ron => ron::from_str(&content).map_err(|e| e.to_string()),
+        Format::Json => serde_json::from_str(&content).map_err(|e| e.to_string()),
+        Format::Yaml => serde_yaml::from_str(&content).map_err(|e| e.to_string()),
+    };
+    result.unwrap_or_else(|e| {
+        log::warn!("failed to parse {}: {e}", path.display());
+        T::default()
+    })
+}
+
+/// Save `value` to `path`, choosing a format from the file's extension.
+/// Errors (serializing or writing) are logged and otherwise ignored.
+pub fn save_state<T: Serialize>(path: impl AsRef<Path>, value: &T) {
+    let path = path.as_ref();
+    if let Err(e) = write_state(path, value) {
+        log::warn!("failed to save state to {}: {e}", path.display());
+    }
+}
+
+fn write_state<T: Serialize>(path: &Path, value: &T) -> io::Result<()> {
+    let content = match Format::from_path(path) {
+        Format::Ron => ron::to_string(value).map_err(io::Error::other),
+        Format::Json => serde_json::to_string_pretty(value).map_err(io::Error::other),
+        Format::Yaml => serde_yaml::to_string(value).map_err(io::Error::other),
+    }?;
+    fs::write(path, content)
+}